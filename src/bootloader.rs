@@ -0,0 +1,163 @@
+use std::{fs, path::Path};
+
+const BLS_ENTRIES_DIR: &str = "/boot/loader/entries";
+const GRUB_CFG_PATHS: &[&str] = &["/boot/grub/grub.cfg", "/boot/grub2/grub.cfg"];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BootEntry {
+    pub title: String,
+    pub version: Option<String>,
+    pub linux: String,
+    pub initrd: Option<String>,
+    pub options: Option<String>,
+}
+
+// Falls back to GRUB's grub.cfg if no BLS entries exist, and returns an
+// empty vec (not an error) if neither source is present, so callers can
+// fall back to scanning `/boot` directly.
+pub fn discover_boot_entries() -> Vec<BootEntry> {
+    let bls_entries = parse_bls_entries(Path::new(BLS_ENTRIES_DIR));
+    if !bls_entries.is_empty() {
+        return bls_entries;
+    }
+
+    for path in GRUB_CFG_PATHS {
+        let entries = parse_grub_cfg(Path::new(path));
+        if !entries.is_empty() {
+            return entries;
+        }
+    }
+
+    Vec::new()
+}
+
+fn parse_bls_entries(dir: &Path) -> Vec<BootEntry> {
+    let Ok(read_dir) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut entries: Vec<BootEntry> = read_dir
+        .flatten()
+        .filter(|entry| entry.path().extension().and_then(|e| e.to_str()) == Some("conf"))
+        .filter_map(|entry| fs::read_to_string(entry.path()).ok())
+        .filter_map(|contents| parse_bls_entry(&contents))
+        .collect();
+
+    entries.sort_by(|a, b| a.title.cmp(&b.title));
+    entries
+}
+
+fn parse_bls_entry(contents: &str) -> Option<BootEntry> {
+    let mut title = None;
+    let mut version = None;
+    let mut linux = None;
+    let mut initrd = None;
+    let mut options = None;
+
+    for line in contents.lines() {
+        let line = line.trim();
+        let Some((key, value)) = line.split_once(char::is_whitespace) else {
+            continue;
+        };
+        let value = value.trim().to_string();
+
+        match key {
+            "title" => title = Some(value),
+            "version" => version = Some(value),
+            "linux" => linux = Some(value),
+            "initrd" => initrd = Some(value),
+            "options" => options = Some(value),
+            _ => {}
+        }
+    }
+
+    Some(BootEntry {
+        title: title.or_else(|| version.clone())?,
+        version,
+        linux: resolve_boot_path(&linux?),
+        initrd: initrd.map(|i| resolve_boot_path(&i)),
+        options,
+    })
+}
+
+fn parse_grub_cfg(path: &Path) -> Vec<BootEntry> {
+    let Ok(contents) = fs::read_to_string(path) else {
+        return Vec::new();
+    };
+
+    let mut entries = Vec::new();
+    let mut title: Option<String> = None;
+    let mut linux: Option<String> = None;
+    let mut initrd: Option<String> = None;
+    let mut options: Option<String> = None;
+
+    for line in contents.lines() {
+        let trimmed = line.trim();
+
+        if let Some(new_title) = extract_menuentry_title(trimmed) {
+            flush_grub_entry(&mut entries, &mut title, &mut linux, &mut initrd, &mut options);
+            title = Some(new_title);
+            continue;
+        }
+
+        if let Some(rest) = strip_any_prefix(trimmed, &["linux ", "linux16 "]) {
+            let mut parts = rest.split_whitespace();
+            linux = parts.next().map(String::from);
+            let rest_args: Vec<&str> = parts.collect();
+            if !rest_args.is_empty() {
+                options = Some(rest_args.join(" "));
+            }
+        } else if let Some(rest) = strip_any_prefix(trimmed, &["initrd ", "initrd16 "]) {
+            initrd = rest.split_whitespace().next().map(String::from);
+        }
+    }
+
+    flush_grub_entry(&mut entries, &mut title, &mut linux, &mut initrd, &mut options);
+    entries
+}
+
+fn strip_any_prefix<'a>(line: &'a str, prefixes: &[&str]) -> Option<&'a str> {
+    prefixes.iter().find_map(|prefix| line.strip_prefix(prefix))
+}
+
+fn extract_menuentry_title(line: &str) -> Option<String> {
+    let rest = line.strip_prefix("menuentry ")?;
+    let quote = rest.chars().next()?;
+    if quote != '\'' && quote != '"' {
+        return None;
+    }
+    let rest = &rest[1..];
+    let end = rest.find(quote)?;
+    Some(rest[..end].to_string())
+}
+
+fn flush_grub_entry(
+    entries: &mut Vec<BootEntry>,
+    title: &mut Option<String>,
+    linux: &mut Option<String>,
+    initrd: &mut Option<String>,
+    options: &mut Option<String>,
+) {
+    if let (Some(title), Some(linux)) = (title.take(), linux.take()) {
+        entries.push(BootEntry {
+            title,
+            version: None,
+            linux: resolve_boot_path(&linux),
+            initrd: initrd.take().map(|i| resolve_boot_path(&i)),
+            options: options.take(),
+        });
+    } else {
+        *initrd = None;
+        *options = None;
+    }
+}
+
+fn resolve_boot_path(raw: &str) -> String {
+    if raw.starts_with("/boot/") {
+        raw.to_string()
+    } else if let Some(stripped) = raw.strip_prefix('/') {
+        format!("/boot/{}", stripped)
+    } else {
+        format!("/boot/{}", raw)
+    }
+}
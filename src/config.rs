@@ -0,0 +1,110 @@
+use serde::Deserialize;
+use std::{
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+};
+
+const SYSTEM_CONFIG_PATH: &str = "/etc/yik/config.toml";
+const USER_CONFIG_RELATIVE: &str = ".config/yik/config.toml";
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Config {
+    #[serde(default)]
+    pub defaults: Defaults,
+    #[serde(default, rename = "profile")]
+    pub profiles: Vec<Profile>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Defaults {
+    #[serde(default)]
+    pub extra_append: Vec<String>,
+    #[serde(default)]
+    pub initrd_glob: Option<String>,
+    #[serde(default)]
+    pub qemu_binary: Option<String>,
+    #[serde(default)]
+    pub qemu_memory_mb: Option<u32>,
+    #[serde(default)]
+    pub qemu_timeout_secs: Option<u64>,
+    #[serde(default)]
+    pub qemu_extra_append: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Profile {
+    pub name: String,
+    #[serde(default)]
+    pub version_regex: Option<String>,
+    #[serde(default)]
+    pub vmlinuz: Option<String>,
+    #[serde(default)]
+    pub initrd: Option<String>,
+    #[serde(default)]
+    pub cmdline: Option<String>,
+    #[serde(default)]
+    pub append: Option<String>,
+    #[serde(default)]
+    pub hidden: bool,
+}
+
+impl Config {
+    // Layers `~/.config/yik/config.toml` on top of `/etc/yik/config.toml`.
+    // Missing files are not an error.
+    pub fn load() -> Result<Config, Box<dyn Error>> {
+        let mut config = Config::default();
+
+        if let Some(system) = Self::load_path(Path::new(SYSTEM_CONFIG_PATH))? {
+            config = system;
+        }
+
+        if let Some(home) = std::env::var_os("HOME") {
+            let user_path = PathBuf::from(home).join(USER_CONFIG_RELATIVE);
+            match Self::load_path(&user_path) {
+                Ok(Some(user)) => {
+                    config.defaults.merge(user.defaults);
+                    config.profiles.extend(user.profiles);
+                }
+                Ok(None) => {}
+                Err(e) => eprintln!("Warning: failed to load user config, ignoring it: {}", e),
+            }
+        }
+
+        Ok(config)
+    }
+
+    fn load_path(path: &Path) -> Result<Option<Config>, Box<dyn Error>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let contents = fs::read_to_string(path)?;
+        let config: Config = toml::from_str(&contents)
+            .map_err(|e| format!("failed to parse {}: {}", path.display(), e))?;
+        Ok(Some(config))
+    }
+}
+
+impl Defaults {
+    fn merge(&mut self, other: Defaults) {
+        if !other.extra_append.is_empty() {
+            self.extra_append = other.extra_append;
+        }
+        if other.initrd_glob.is_some() {
+            self.initrd_glob = other.initrd_glob;
+        }
+        if other.qemu_binary.is_some() {
+            self.qemu_binary = other.qemu_binary;
+        }
+        if other.qemu_memory_mb.is_some() {
+            self.qemu_memory_mb = other.qemu_memory_mb;
+        }
+        if other.qemu_timeout_secs.is_some() {
+            self.qemu_timeout_secs = other.qemu_timeout_secs;
+        }
+        if !other.qemu_extra_append.is_empty() {
+            self.qemu_extra_append = other.qemu_extra_append;
+        }
+    }
+}
@@ -11,34 +11,396 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
     Frame, Terminal,
 };
+use regex::Regex;
 use std::{
+    collections::HashSet,
     error::Error,
     fs,
-    io::{self, Write},
+    io::{self, BufRead, BufReader, Write},
     path::Path,
-    process::Command,
+    process::{Command, Stdio},
+    sync::mpsc,
+    thread,
+    time::{Duration, Instant},
 };
 
+mod bootloader;
+mod config;
+
 #[derive(Debug, Clone, PartialEq)]
 enum AppState {
     SelectingKernel,
-    ConfirmingSwitch(String), // Contains the selected kernel version
+    ConfirmingSwitch(String),  // Contains the selected kernel version
+    TestingBoot(String),       // Contains the kernel version being smoke-tested in QEMU
+    EditingCmdline(String),    // Contains the kernel version whose cmdline is being edited
+    ActionMenu,                // Power/boot-management actions beyond picking a kernel
+    ConfirmingAction(ActionMenuItem), // A consequential action menu item awaiting y/n confirmation
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ActionMenuItem {
+    KexecSelected,
+    RebootNormally,
+    Poweroff,
+    RevertStagedKexec,
+}
+
+const ACTION_MENU_ITEMS: &[ActionMenuItem] = &[
+    ActionMenuItem::KexecSelected,
+    ActionMenuItem::RebootNormally,
+    ActionMenuItem::Poweroff,
+    ActionMenuItem::RevertStagedKexec,
+];
+
+impl ActionMenuItem {
+    fn label(&self) -> &'static str {
+        match self {
+            ActionMenuItem::KexecSelected => "kexec into selected kernel",
+            ActionMenuItem::RebootNormally => "Reboot normally",
+            ActionMenuItem::Poweroff => "Power off",
+            ActionMenuItem::RevertStagedKexec => "Revert staged kexec (kexec -u)",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum CmdlineEditAction {
+    None,
+    Adding(String),
+    Editing(usize, String),
+}
+
+// Markers in the serial output that mean the kernel reached userspace.
+const BOOT_SUCCESS_MARKERS: &[&str] = &["login:", "Welcome to", "/ #", "systemd[1]: Startup finished"];
+
+// Markers that mean the kernel failed before reaching userspace, so we can
+// bail out early instead of waiting out the full timeout.
+const BOOT_FAILURE_MARKERS: &[&str] = &["Kernel panic", "Unable to mount root fs"];
+
+#[derive(Debug)]
+enum BootTestOutcome {
+    Passed,
+    Failed(String),
+    TimedOut,
+}
+
+// Environment variables take priority, then `[defaults]`, then these
+// hardcoded fallbacks.
+struct QemuTestConfig {
+    binary: String,
+    memory_mb: u32,
+    extra_append: Vec<String>,
+    timeout: Duration,
+}
+
+impl QemuTestConfig {
+    fn resolve(defaults: &config::Defaults) -> QemuTestConfig {
+        let binary = std::env::var("YIK_QEMU_BIN")
+            .ok()
+            .or_else(|| defaults.qemu_binary.clone())
+            .unwrap_or_else(default_qemu_binary);
+        let memory_mb = std::env::var("YIK_QEMU_MEMORY_MB")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .or(defaults.qemu_memory_mb)
+            .unwrap_or(512);
+        let extra_append = std::env::var("YIK_QEMU_EXTRA_APPEND")
+            .ok()
+            .map(|v| v.split_whitespace().map(String::from).collect())
+            .unwrap_or_else(|| defaults.qemu_extra_append.clone());
+        let timeout_secs = std::env::var("YIK_QEMU_TIMEOUT_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .or(defaults.qemu_timeout_secs)
+            .unwrap_or(30);
+
+        QemuTestConfig {
+            binary,
+            memory_mb,
+            extra_append,
+            timeout: Duration::from_secs(timeout_secs),
+        }
+    }
+}
+
+fn default_qemu_binary() -> String {
+    format!("qemu-system-{}", std::env::consts::ARCH)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum CmdlineOverride {
+    None,
+    Append(String),
+    Replace(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct KernelEntry {
+    version: String,
+    display_name: String,
+    vmlinuz_path: Option<String>,
+    initrd_path: Option<String>,
+    cmdline_override: CmdlineOverride,
+}
+
+impl KernelEntry {
+    fn vmlinuz(&self) -> String {
+        self.vmlinuz_path
+            .clone()
+            .unwrap_or_else(|| format!("/boot/vmlinuz-{}", self.version))
+    }
+
+    fn initrd(&self, defaults: &config::Defaults) -> Result<String, Box<dyn Error>> {
+        match &self.initrd_path {
+            Some(path) => Ok(path.clone()),
+            None => find_initrd_file(&self.version, defaults.initrd_glob.as_deref()),
+        }
+    }
+
+    fn resolve_cmdline(&self, base_cmdline: &str) -> String {
+        match &self.cmdline_override {
+            CmdlineOverride::None => base_cmdline.to_string(),
+            CmdlineOverride::Append(extra) => format!("{} {}", base_cmdline, extra),
+            CmdlineOverride::Replace(full) => full.clone(),
+        }
+    }
+}
+
+fn build_cmdline_override(profile: Option<&config::Profile>, defaults: &config::Defaults) -> CmdlineOverride {
+    let mut append_parts = defaults.extra_append.clone();
+
+    if let Some(profile) = profile {
+        if let Some(full) = &profile.cmdline {
+            return CmdlineOverride::Replace(full.clone());
+        }
+        if let Some(extra) = &profile.append {
+            append_parts.push(extra.clone());
+        }
+    }
+
+    if append_parts.is_empty() {
+        CmdlineOverride::None
+    } else {
+        CmdlineOverride::Append(append_parts.join(" "))
+    }
+}
+
+// Merges `/boot`-discovered versions with configured profiles; a profile
+// with an explicit vmlinuz but no matching discovered version is added as
+// its own standalone entry.
+fn merge_kernel_entries(discovered_versions: Vec<String>, config: &config::Config) -> Vec<KernelEntry> {
+    let mut entries = Vec::new();
+    let mut matched_versions = HashSet::new();
+
+    for version in &discovered_versions {
+        let profile = config.profiles.iter().find(|p| {
+            p.version_regex
+                .as_deref()
+                .and_then(|pattern| Regex::new(pattern).ok())
+                .map(|re| re.is_match(version))
+                .unwrap_or(false)
+        });
+
+        if let Some(profile) = profile {
+            matched_versions.insert(version.clone());
+            if profile.hidden {
+                continue;
+            }
+            entries.push(KernelEntry {
+                version: version.clone(),
+                display_name: profile.name.clone(),
+                vmlinuz_path: profile.vmlinuz.clone(),
+                initrd_path: profile.initrd.clone(),
+                cmdline_override: build_cmdline_override(Some(profile), &config.defaults),
+            });
+        } else {
+            entries.push(KernelEntry {
+                version: version.clone(),
+                display_name: version.clone(),
+                vmlinuz_path: None,
+                initrd_path: None,
+                cmdline_override: build_cmdline_override(None, &config.defaults),
+            });
+        }
+    }
+
+    for profile in &config.profiles {
+        let pins_a_discovered_version = profile
+            .version_regex
+            .as_deref()
+            .and_then(|pattern| Regex::new(pattern).ok())
+            .map(|re| matched_versions.iter().any(|v| re.is_match(v)))
+            .unwrap_or(false);
+
+        if !pins_a_discovered_version && !profile.hidden && profile.vmlinuz.is_some() {
+            entries.push(KernelEntry {
+                version: profile.name.clone(),
+                display_name: profile.name.clone(),
+                vmlinuz_path: profile.vmlinuz.clone(),
+                initrd_path: profile.initrd.clone(),
+                cmdline_override: build_cmdline_override(Some(profile), &config.defaults),
+            });
+        }
+    }
+
+    entries
+}
+
+// Applies a matching profile (if any) on top of a bootloader-discovered
+// entry, which already carries its own kernel/initrd paths and cmdline.
+fn apply_profile_to_boot_entry(
+    entry: bootloader::BootEntry,
+    profile: Option<&config::Profile>,
+    defaults: &config::Defaults,
+) -> KernelEntry {
+    let version = entry.version.unwrap_or_else(|| entry.title.clone());
+
+    let mut cmdline_override = match entry.options {
+        Some(options) => CmdlineOverride::Replace(options),
+        None => CmdlineOverride::None,
+    };
+    if !defaults.extra_append.is_empty() {
+        let extra = defaults.extra_append.join(" ");
+        cmdline_override = match cmdline_override {
+            CmdlineOverride::Replace(base) => CmdlineOverride::Replace(format!("{} {}", base, extra)),
+            CmdlineOverride::None | CmdlineOverride::Append(_) => CmdlineOverride::Append(extra),
+        };
+    }
+
+    let mut display_name = entry.title;
+    let mut vmlinuz_path = Some(entry.linux);
+    let mut initrd_path = entry.initrd;
+
+    if let Some(profile) = profile {
+        display_name = profile.name.clone();
+        if profile.vmlinuz.is_some() {
+            vmlinuz_path = profile.vmlinuz.clone();
+        }
+        if profile.initrd.is_some() {
+            initrd_path = profile.initrd.clone();
+        }
+        if let Some(full) = &profile.cmdline {
+            cmdline_override = CmdlineOverride::Replace(full.clone());
+        } else if let Some(extra) = &profile.append {
+            cmdline_override = match cmdline_override {
+                CmdlineOverride::Replace(base) => {
+                    CmdlineOverride::Replace(format!("{} {}", base, extra))
+                }
+                CmdlineOverride::None | CmdlineOverride::Append(_) => {
+                    CmdlineOverride::Append(extra.clone())
+                }
+            };
+        }
+    }
+
+    KernelEntry {
+        version,
+        display_name,
+        vmlinuz_path,
+        initrd_path,
+        cmdline_override,
+    }
+}
+
+fn merge_boot_entries(boot_entries: Vec<bootloader::BootEntry>, config: &config::Config) -> Vec<KernelEntry> {
+    let mut entries = Vec::new();
+    let mut matched_keys = HashSet::new();
+
+    for boot_entry in boot_entries {
+        let match_key = boot_entry
+            .version
+            .clone()
+            .unwrap_or_else(|| boot_entry.title.clone());
+        let profile = config.profiles.iter().find(|p| {
+            p.version_regex
+                .as_deref()
+                .and_then(|pattern| Regex::new(pattern).ok())
+                .map(|re| re.is_match(&match_key))
+                .unwrap_or(false)
+        });
+
+        if let Some(profile) = profile {
+            matched_keys.insert(match_key);
+            if profile.hidden {
+                continue;
+            }
+        }
+
+        entries.push(apply_profile_to_boot_entry(boot_entry, profile, &config.defaults));
+    }
+
+    for profile in &config.profiles {
+        let pins_a_discovered_entry = profile
+            .version_regex
+            .as_deref()
+            .and_then(|pattern| Regex::new(pattern).ok())
+            .map(|re| matched_keys.iter().any(|k| re.is_match(k)))
+            .unwrap_or(false);
+
+        if !pins_a_discovered_entry && !profile.hidden && profile.vmlinuz.is_some() {
+            entries.push(KernelEntry {
+                version: profile.name.clone(),
+                display_name: profile.name.clone(),
+                vmlinuz_path: profile.vmlinuz.clone(),
+                initrd_path: profile.initrd.clone(),
+                cmdline_override: build_cmdline_override(Some(profile), &config.defaults),
+            });
+        }
+    }
+
+    // Sort newest-first the same way `get_kernel_versions` does, rather than
+    // leaving entries in whatever order the bootloader's own config listed
+    // them (BLS sorts by title, which isn't a kernel-version ordering).
+    entries.sort_by(|a, b| compare_kernel_versions(&b.version, &a.version));
+
+    entries
 }
 
 struct App {
-    kernel_versions: Vec<String>,
+    kernel_versions: Vec<KernelEntry>,
     current_kernel: Option<String>,
     list_state: ListState,
     state: AppState,
+    cmdline_tokens: Vec<String>,
+    cmdline_list_state: ListState,
+    cmdline_edit: CmdlineEditAction,
+    config: config::Config,
+    action_menu_state: ListState,
 }
 
 impl App {
     fn new() -> Result<App, Box<dyn Error>> {
-        let kernel_versions = get_kernel_versions()?;
+        let config = config::Config::load().unwrap_or_else(|e| {
+            eprintln!("Warning: failed to load config, using defaults: {}", e);
+            config::Config::default()
+        });
+
+        // Prefer entries straight from the bootloader (BLS, or GRUB as a
+        // fallback): they carry the exact kernel/initrd paths and command
+        // line the bootloader itself would use, rather than ones we'd have
+        // to reconstruct by scraping `/boot` filenames and `/proc/cmdline`.
+        let boot_entries = bootloader::discover_boot_entries();
+        let kernel_versions = if boot_entries.is_empty() {
+            let discovered_versions = get_kernel_versions()?;
+            merge_kernel_entries(discovered_versions, &config)
+        } else {
+            merge_boot_entries(boot_entries, &config)
+        };
+        if kernel_versions.is_empty() {
+            return Err("No kernel versions found".into());
+        }
+
         let current_kernel = get_current_kernel().ok();
         let mut list_state = ListState::default();
         if !kernel_versions.is_empty() {
-            list_state.select(Some(0));
+            // Pre-highlight the newest kernel that isn't already running,
+            // since that's the upgrade candidate the user almost always
+            // wants -- not whatever happened to sort first.
+            let preselected = kernel_versions
+                .iter()
+                .position(|entry| Some(entry.version.as_str()) != current_kernel.as_deref())
+                .unwrap_or(0);
+            list_state.select(Some(preselected));
         }
 
         Ok(App {
@@ -46,11 +408,16 @@ impl App {
             current_kernel,
             list_state,
             state: AppState::SelectingKernel,
+            cmdline_tokens: Vec::new(),
+            cmdline_list_state: ListState::default(),
+            cmdline_edit: CmdlineEditAction::None,
+            config,
+            action_menu_state: ListState::default(),
         })
     }
 
     fn next(&mut self) {
-        if self.state != AppState::SelectingKernel {
+        if self.state != AppState::SelectingKernel || self.kernel_versions.is_empty() {
             return;
         }
 
@@ -68,7 +435,7 @@ impl App {
     }
 
     fn previous(&mut self) {
-        if self.state != AppState::SelectingKernel {
+        if self.state != AppState::SelectingKernel || self.kernel_versions.is_empty() {
             return;
         }
 
@@ -89,25 +456,260 @@ impl App {
         if self.state != AppState::SelectingKernel {
             return Ok(());
         }
+        self.load_selected_kernel()
+    }
 
-        if let Some(i) = self.list_state.selected() {
-            if let Some(version) = self.kernel_versions.get(i) {
-                print!("Loading kernel version: {}... ", version);
-                io::stdout().flush()?;
+    // Shared by the main list's Enter key and the action menu's "kexec into
+    // selected kernel" entry.
+    fn load_selected_kernel(&mut self) -> Result<(), Box<dyn Error>> {
+        let entry = self
+            .list_state
+            .selected()
+            .and_then(|i| self.kernel_versions.get(i));
+        let Some(entry) = entry else {
+            return Ok(());
+        };
 
-                match execute_kexec_load(version) {
-                    Ok(_) => {
-                        println!("Success!");
-                        self.state = AppState::ConfirmingSwitch(version.clone());
-                    }
-                    Err(e) => {
-                        println!("Failed: {}", e);
-                        eprintln!("Press Enter to continue...");
-                        let mut input = String::new();
-                        io::stdin().read_line(&mut input)?;
+        print!("Loading kernel version: {}... ", entry.display_name);
+        io::stdout().flush()?;
+
+        let base_cmdline = get_cmdline().unwrap_or_default();
+        let cmdline = entry.resolve_cmdline(&base_cmdline);
+        let vmlinuz_path = entry.vmlinuz();
+        let version = entry.version.clone();
+        let result = entry
+            .initrd(&self.config.defaults)
+            .and_then(|initrd_path| execute_kexec_load(&vmlinuz_path, &initrd_path, &cmdline));
+
+        match result {
+            Ok(_) => {
+                println!("Success!");
+                self.state = AppState::ConfirmingSwitch(version);
+            }
+            Err(e) => {
+                println!("Failed: {}", e);
+                eprintln!("Press Enter to continue...");
+                let mut input = String::new();
+                io::stdin().read_line(&mut input)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn start_boot_test(&mut self) {
+        if self.state != AppState::SelectingKernel {
+            return;
+        }
+
+        if let Some(entry) = self
+            .list_state
+            .selected()
+            .and_then(|i| self.kernel_versions.get(i))
+        {
+            self.state = AppState::TestingBoot(entry.version.clone());
+        }
+    }
+
+    fn run_boot_test(&mut self) -> Result<(), Box<dyn Error>> {
+        if let AppState::TestingBoot(version) = self.state.clone() {
+            println!("Booting kernel {} under QEMU for a smoke test...\n", version);
+            io::stdout().flush()?;
+
+            let entry = self
+                .kernel_versions
+                .iter()
+                .find(|e| e.version == version)
+                .cloned();
+            let qemu_config = QemuTestConfig::resolve(&self.config.defaults);
+
+            let outcome = match entry {
+                Some(entry) => boot_test_kernel(&entry, &self.config.defaults, &qemu_config),
+                None => Err(format!("kernel entry for {} is no longer available", version).into()),
+            };
+
+            match outcome {
+                Ok(BootTestOutcome::Passed) => {
+                    println!("\nSmoke test PASSED: kernel {} reached userspace.", version);
+                }
+                Ok(BootTestOutcome::Failed(reason)) => {
+                    println!("\nSmoke test FAILED for kernel {}: {}", version, reason);
+                }
+                Ok(BootTestOutcome::TimedOut) => {
+                    println!(
+                        "\nSmoke test TIMED OUT after {}s waiting for kernel {} to boot.",
+                        qemu_config.timeout.as_secs(),
+                        version
+                    );
+                }
+                Err(e) => {
+                    println!("\nFailed to run smoke test: {}", e);
+                }
+            }
+
+            eprintln!("\nPress Enter to continue...");
+            let mut input = String::new();
+            io::stdin().read_line(&mut input)?;
+
+            self.state = AppState::SelectingKernel;
+        }
+        Ok(())
+    }
+
+    fn start_editing_cmdline(&mut self) {
+        if self.state != AppState::SelectingKernel {
+            return;
+        }
+
+        if let Some(entry) = self
+            .list_state
+            .selected()
+            .and_then(|i| self.kernel_versions.get(i))
+        {
+            let base_cmdline = get_cmdline().unwrap_or_default();
+            let cmdline = entry.resolve_cmdline(&base_cmdline);
+            let version = entry.version.clone();
+            self.cmdline_tokens = parse_cmdline_tokens(&cmdline);
+            self.cmdline_list_state = ListState::default();
+            if !self.cmdline_tokens.is_empty() {
+                self.cmdline_list_state.select(Some(0));
+            }
+            self.cmdline_edit = CmdlineEditAction::None;
+            self.state = AppState::EditingCmdline(version);
+        }
+    }
+
+    fn cancel_cmdline_edit(&mut self) {
+        self.cmdline_edit = CmdlineEditAction::None;
+        self.state = AppState::SelectingKernel;
+    }
+
+    fn cmdline_next(&mut self) {
+        if self.cmdline_tokens.is_empty() {
+            return;
+        }
+        let i = match self.cmdline_list_state.selected() {
+            Some(i) if i + 1 < self.cmdline_tokens.len() => i + 1,
+            _ => 0,
+        };
+        self.cmdline_list_state.select(Some(i));
+    }
+
+    fn cmdline_previous(&mut self) {
+        if self.cmdline_tokens.is_empty() {
+            return;
+        }
+        let i = match self.cmdline_list_state.selected() {
+            Some(0) | None => self.cmdline_tokens.len() - 1,
+            Some(i) => i - 1,
+        };
+        self.cmdline_list_state.select(Some(i));
+    }
+
+    fn cmdline_start_add(&mut self) {
+        self.cmdline_edit = CmdlineEditAction::Adding(String::new());
+    }
+
+    fn cmdline_start_edit_selected(&mut self) {
+        if let Some((i, token)) = self
+            .cmdline_list_state
+            .selected()
+            .and_then(|i| self.cmdline_tokens.get(i).map(|token| (i, token.clone())))
+        {
+            self.cmdline_edit = CmdlineEditAction::Editing(i, token);
+        }
+    }
+
+    fn cmdline_delete_selected(&mut self) {
+        if let Some(i) = self
+            .cmdline_list_state
+            .selected()
+            .filter(|&i| i < self.cmdline_tokens.len())
+        {
+            self.cmdline_tokens.remove(i);
+            if self.cmdline_tokens.is_empty() {
+                self.cmdline_list_state.select(None);
+            } else {
+                self.cmdline_list_state
+                    .select(Some(i.min(self.cmdline_tokens.len() - 1)));
+            }
+        }
+    }
+
+    fn cmdline_input_char(&mut self, c: char) {
+        match &mut self.cmdline_edit {
+            CmdlineEditAction::Adding(s) | CmdlineEditAction::Editing(_, s) => s.push(c),
+            CmdlineEditAction::None => {}
+        }
+    }
+
+    fn cmdline_input_backspace(&mut self) {
+        match &mut self.cmdline_edit {
+            CmdlineEditAction::Adding(s) | CmdlineEditAction::Editing(_, s) => {
+                s.pop();
+            }
+            CmdlineEditAction::None => {}
+        }
+    }
+
+    fn cmdline_input_cancel(&mut self) {
+        self.cmdline_edit = CmdlineEditAction::None;
+    }
+
+    fn cmdline_input_confirm(&mut self) {
+        match std::mem::replace(&mut self.cmdline_edit, CmdlineEditAction::None) {
+            CmdlineEditAction::Adding(s) => {
+                let s = s.trim().to_string();
+                if !s.is_empty() {
+                    self.cmdline_tokens.push(s);
+                    self.cmdline_list_state
+                        .select(Some(self.cmdline_tokens.len() - 1));
+                }
+            }
+            CmdlineEditAction::Editing(i, s) => {
+                let s = s.trim().to_string();
+                if let Some(token) = self.cmdline_tokens.get_mut(i) {
+                    if s.is_empty() {
+                        self.cmdline_tokens.remove(i);
+                    } else {
+                        *token = s;
                     }
                 }
             }
+            CmdlineEditAction::None => {}
+        }
+    }
+
+    fn apply_cmdline_and_load(&mut self) -> Result<(), Box<dyn Error>> {
+        if let AppState::EditingCmdline(version) = self.state.clone() {
+            let cmdline = self.cmdline_tokens.join(" ");
+            let entry = self
+                .kernel_versions
+                .iter()
+                .find(|e| e.version == version)
+                .cloned();
+
+            print!("Loading kernel version: {} with edited cmdline... ", version);
+            io::stdout().flush()?;
+
+            let result = match entry {
+                Some(entry) => entry
+                    .initrd(&self.config.defaults)
+                    .and_then(|initrd_path| execute_kexec_load(&entry.vmlinuz(), &initrd_path, &cmdline)),
+                None => Err(format!("kernel entry for {} is no longer available", version).into()),
+            };
+
+            match result {
+                Ok(_) => {
+                    println!("Success!");
+                    self.state = AppState::ConfirmingSwitch(version);
+                }
+                Err(e) => {
+                    println!("Failed: {}", e);
+                    eprintln!("Press Enter to continue...");
+                    let mut input = String::new();
+                    io::stdin().read_line(&mut input)?;
+                }
+            }
         }
         Ok(())
     }
@@ -143,6 +745,103 @@ impl App {
         }
         Ok(())
     }
+
+    fn start_action_menu(&mut self) {
+        if self.state != AppState::SelectingKernel {
+            return;
+        }
+        self.action_menu_state = ListState::default();
+        self.action_menu_state.select(Some(0));
+        self.state = AppState::ActionMenu;
+    }
+
+    fn cancel_action_menu(&mut self) {
+        self.state = AppState::SelectingKernel;
+    }
+
+    fn action_menu_next(&mut self) {
+        let i = match self.action_menu_state.selected() {
+            Some(i) if i + 1 < ACTION_MENU_ITEMS.len() => i + 1,
+            _ => 0,
+        };
+        self.action_menu_state.select(Some(i));
+    }
+
+    fn action_menu_previous(&mut self) {
+        let i = match self.action_menu_state.selected() {
+            Some(0) | None => ACTION_MENU_ITEMS.len() - 1,
+            Some(i) => i - 1,
+        };
+        self.action_menu_state.select(Some(i));
+    }
+
+    fn run_action_menu_selection(&mut self) -> Result<(), Box<dyn Error>> {
+        let item = match self.action_menu_state.selected().and_then(|i| ACTION_MENU_ITEMS.get(i)) {
+            Some(item) => *item,
+            None => return Ok(()),
+        };
+
+        match item {
+            ActionMenuItem::KexecSelected => self.load_selected_kernel(),
+            // Reboot and poweroff are just as irreversible as a kexec switch,
+            // so they get the same y/n confirmation as `ConfirmingSwitch`
+            // instead of running on a single Enter keypress.
+            ActionMenuItem::RebootNormally | ActionMenuItem::Poweroff => {
+                self.state = AppState::ConfirmingAction(item);
+                Ok(())
+            }
+            ActionMenuItem::RevertStagedKexec => {
+                self.run_simple_action("Reverting staged kexec image...", execute_kexec_unload)
+            }
+        }
+    }
+
+    fn confirm_action(&mut self) -> Result<(), Box<dyn Error>> {
+        if let AppState::ConfirmingAction(item) = self.state {
+            print!("\nAre you sure you want to: {}? (y/N): ", item.label());
+            io::stdout().flush()?;
+
+            let mut input = String::new();
+            io::stdin().read_line(&mut input)?;
+            let input = input.trim().to_lowercase();
+
+            if ["y", "yes"].contains(&input.as_str()) {
+                match item {
+                    ActionMenuItem::RebootNormally => {
+                        self.run_simple_action("Rebooting...", execute_systemctl_reboot)?;
+                    }
+                    ActionMenuItem::Poweroff => {
+                        self.run_simple_action("Powering off...", execute_poweroff)?;
+                    }
+                    ActionMenuItem::KexecSelected | ActionMenuItem::RevertStagedKexec => {}
+                }
+            }
+
+            self.state = AppState::SelectingKernel;
+        }
+        Ok(())
+    }
+
+    fn run_simple_action(
+        &mut self,
+        progress_message: &str,
+        action: fn() -> Result<(), Box<dyn Error>>,
+    ) -> Result<(), Box<dyn Error>> {
+        println!("{}", progress_message);
+        io::stdout().flush()?;
+
+        match action() {
+            Ok(_) => println!("Done."),
+            Err(e) => println!("Failed: {}", e),
+        }
+
+        eprintln!("Press Enter to continue...");
+        let mut input = String::new();
+        io::stdin().read_line(&mut input)?;
+
+        self.state = AppState::SelectingKernel;
+        Ok(())
+    }
 }
 
 fn get_kernel_versions() -> Result<Vec<String>, Box<dyn Error>> {
@@ -169,8 +868,9 @@ fn get_kernel_versions() -> Result<Vec<String>, Box<dyn Error>> {
         }
     }
 
-    // Sort versions
-    kernel_versions.sort();
+    // Sort newest-first using real version-component comparison, not a
+    // lexical sort (which would put 5.10.0 after 5.9.0).
+    kernel_versions.sort_by(|a, b| compare_kernel_versions(b, a));
 
     if kernel_versions.is_empty() {
         return Err("No kernel versions found in /boot directory".into());
@@ -179,6 +879,90 @@ fn get_kernel_versions() -> Result<Vec<String>, Box<dyn Error>> {
     Ok(kernel_versions)
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum VersionToken {
+    Numeric(u64),
+    Text(String),
+}
+
+// Splits into alternating numeric/non-numeric runs, e.g. "6.1.0-12-amd64"
+// -> [6, ".", 1, ".", 0, "-", 12, "-amd64"].
+fn tokenize_version(version: &str) -> Vec<VersionToken> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut in_digits = false;
+
+    for c in version.chars() {
+        if c.is_ascii_digit() != in_digits && !current.is_empty() {
+            tokens.push(if in_digits {
+                VersionToken::Numeric(current.parse().unwrap_or(0))
+            } else {
+                VersionToken::Text(current.clone())
+            });
+            current.clear();
+        }
+        in_digits = c.is_ascii_digit();
+        current.push(c);
+    }
+
+    if !current.is_empty() {
+        tokens.push(if in_digits {
+            VersionToken::Numeric(current.parse().unwrap_or(0))
+        } else {
+            VersionToken::Text(current)
+        });
+    }
+
+    tokens
+}
+
+fn split_prerelease(version: &str) -> (&str, bool) {
+    let lowered = version.to_lowercase();
+    for marker in ["-rc", "-alpha", "-beta"] {
+        if let Some(idx) = lowered.find(marker) {
+            return (&version[..idx], true);
+        }
+    }
+    (version, false)
+}
+
+// Ascending (oldest-first) order; a pre-release suffix (-rc2, ...) sorts
+// before the final release it leads up to.
+fn compare_kernel_versions(a: &str, b: &str) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+
+    let (a_base, a_is_pre) = split_prerelease(a);
+    let (b_base, b_is_pre) = split_prerelease(b);
+
+    let a_tokens = tokenize_version(a_base);
+    let b_tokens = tokenize_version(b_base);
+
+    for pair in a_tokens.iter().zip(b_tokens.iter()) {
+        let ordering = match pair {
+            (VersionToken::Numeric(x), VersionToken::Numeric(y)) => x.cmp(y),
+            (VersionToken::Text(x), VersionToken::Text(y)) => x.cmp(y),
+            // A numeric run at a position where the other version has a
+            // non-numeric run is treated as the more "final" one.
+            (VersionToken::Numeric(_), VersionToken::Text(_)) => Ordering::Greater,
+            (VersionToken::Text(_), VersionToken::Numeric(_)) => Ordering::Less,
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+
+    let len_ordering = a_tokens.len().cmp(&b_tokens.len());
+    if len_ordering != Ordering::Equal {
+        return len_ordering;
+    }
+
+    match (a_is_pre, b_is_pre) {
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        _ => a.cmp(b),
+    }
+}
+
 fn get_current_kernel() -> Result<String, Box<dyn Error>> {
     let output = Command::new("uname").arg("-r").output()?;
 
@@ -195,36 +979,55 @@ fn get_cmdline() -> Result<String, Box<dyn Error>> {
     Ok(cmdline.trim().to_string())
 }
 
-fn find_initrd_file(version: &str) -> Result<String, Box<dyn Error>> {
+// Matches a glob with at most one `*` wildcard -- enough for an
+// `initrd_glob` hint like "initrd.img-*" without a full glob crate.
+fn glob_matches(pattern: &str, text: &str) -> bool {
+    match pattern.split_once('*') {
+        Some((prefix, suffix)) => {
+            text.len() >= prefix.len() + suffix.len()
+                && text.starts_with(prefix)
+                && text.ends_with(suffix)
+        }
+        None => pattern == text,
+    }
+}
+
+fn find_initrd_file(version: &str, preferred_glob: Option<&str>) -> Result<String, Box<dyn Error>> {
     let boot_path = Path::new("/boot");
     let entries = fs::read_dir(boot_path)?;
 
-    // Look for initrd files that match the version
+    let mut fallback = None;
     for entry in entries {
         let entry = entry?;
         let file_name = entry.file_name();
         let file_name_str = file_name.to_string_lossy();
 
+        if preferred_glob
+            .is_some_and(|pattern| file_name_str.contains(version) && glob_matches(pattern, &file_name_str))
+        {
+            return Ok(format!("/boot/{}", file_name_str));
+        }
+
         // Check for different initrd naming patterns
         if (file_name_str.starts_with("initrd.img-") && file_name_str.contains(version))
             || (file_name_str.starts_with("initramfs-") && file_name_str.contains(version))
         {
-            return Ok(format!("/boot/{}", file_name_str));
+            fallback.get_or_insert_with(|| format!("/boot/{}", file_name_str));
         }
     }
 
-    Err(format!("No initrd file found for version {}", version).into())
+    fallback.ok_or_else(|| format!("No initrd file found for version {}", version).into())
 }
 
-fn execute_kexec_load(version: &str) -> Result<(), Box<dyn Error>> {
-    let vmlinuz_path = format!("/boot/vmlinuz-{}", version);
-    let initrd_path = find_initrd_file(version)?;
-    let cmdline = get_cmdline()?;
+fn parse_cmdline_tokens(cmdline: &str) -> Vec<String> {
+    cmdline.split_whitespace().map(String::from).collect()
+}
 
+fn execute_kexec_load(vmlinuz_path: &str, initrd_path: &str, cmdline: &str) -> Result<(), Box<dyn Error>> {
     let output = Command::new("sudo")
         .arg("kexec")
         .arg("-l")
-        .arg(&vmlinuz_path)
+        .arg(vmlinuz_path)
         .arg(format!("--initrd={}", initrd_path))
         .arg(format!("--command-line={}", cmdline))
         .output()?;
@@ -237,6 +1040,93 @@ fn execute_kexec_load(version: &str) -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+// Boots `entry` in a throwaway QEMU VM and watches its serial console for a
+// sign that it reached userspace, without touching the real kexec state.
+fn boot_test_kernel(
+    entry: &KernelEntry,
+    defaults: &config::Defaults,
+    config: &QemuTestConfig,
+) -> Result<BootTestOutcome, Box<dyn Error>> {
+    let vmlinuz_path = entry.vmlinuz();
+    let initrd_path = entry.initrd(defaults)?;
+    let base_cmdline = get_cmdline().unwrap_or_default();
+    let mut cmdline = entry.resolve_cmdline(&base_cmdline);
+    cmdline.push_str(" console=ttyS0");
+    for arg in &config.extra_append {
+        cmdline.push(' ');
+        cmdline.push_str(arg);
+    }
+
+    let mut child = Command::new(&config.binary)
+        .arg("-kernel")
+        .arg(&vmlinuz_path)
+        .arg("-initrd")
+        .arg(&initrd_path)
+        .arg("-append")
+        .arg(&cmdline)
+        .arg("-m")
+        .arg(config.memory_mb.to_string())
+        .arg("-nographic")
+        .arg("-no-reboot")
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    let stdout = child.stdout.take().ok_or("failed to capture QEMU stdout")?;
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let reader = BufReader::new(stdout);
+        for line in reader.lines().map_while(Result::ok) {
+            if tx.send(line).is_err() {
+                break;
+            }
+        }
+    });
+
+    // QEMU's stderr is piped too (for diagnostics), so it must be drained on
+    // its own thread the same way stdout is -- otherwise enough output there
+    // fills the OS pipe buffer and QEMU blocks on write, hanging the smoke
+    // test for reasons unrelated to whether the kernel actually booted.
+    if let Some(stderr) = child.stderr.take() {
+        thread::spawn(move || {
+            let reader = BufReader::new(stderr);
+            for line in reader.lines().map_while(Result::ok) {
+                eprintln!("{}", line);
+            }
+        });
+    }
+
+    let deadline = Instant::now() + config.timeout;
+    let outcome = loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            break BootTestOutcome::TimedOut;
+        }
+
+        match rx.recv_timeout(remaining) {
+            Ok(line) => {
+                println!("{}", line);
+                if BOOT_FAILURE_MARKERS.iter().any(|m| line.contains(m)) {
+                    break BootTestOutcome::Failed(line);
+                }
+                if BOOT_SUCCESS_MARKERS.iter().any(|m| line.contains(m)) {
+                    break BootTestOutcome::Passed;
+                }
+            }
+            Err(mpsc::RecvTimeoutError::Timeout) => break BootTestOutcome::TimedOut,
+            Err(mpsc::RecvTimeoutError::Disconnected) => {
+                break BootTestOutcome::Failed("QEMU exited before producing a boot marker".into())
+            }
+        }
+    };
+
+    let _ = child.kill();
+    let _ = child.wait();
+
+    Ok(outcome)
+}
+
 fn execute_kexec_execute() -> Result<(), Box<dyn Error>> {
     let output = Command::new("sudo").arg("kexec").arg("-e").output()?;
 
@@ -248,6 +1138,39 @@ fn execute_kexec_execute() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+fn execute_kexec_unload() -> Result<(), Box<dyn Error>> {
+    let output = Command::new("sudo").arg("kexec").arg("-u").output()?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("kexec unload failed: {}", stderr).into());
+    }
+
+    Ok(())
+}
+
+fn execute_systemctl_reboot() -> Result<(), Box<dyn Error>> {
+    let output = Command::new("systemctl").arg("reboot").output()?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("systemctl reboot failed: {}", stderr).into());
+    }
+
+    Ok(())
+}
+
+fn execute_poweroff() -> Result<(), Box<dyn Error>> {
+    let output = Command::new("poweroff").output()?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("poweroff failed: {}", stderr).into());
+    }
+
+    Ok(())
+}
+
 fn main() -> Result<(), Box<dyn Error>> {
     // Setup terminal
     enable_raw_mode()?;
@@ -320,11 +1243,145 @@ fn run_app<B: Backend + std::io::Write>(
             continue;
         }
 
+        // Handle action-menu confirmation (reboot/poweroff) the same way as
+        // ConfirmingSwitch above.
+        if let AppState::ConfirmingAction(_) = &app.state {
+            disable_raw_mode()?;
+            execute!(
+                terminal.backend_mut(),
+                LeaveAlternateScreen,
+                DisableMouseCapture
+            )?;
+            terminal.show_cursor()?;
+
+            app.confirm_action()?;
+
+            enable_raw_mode()?;
+            execute!(
+                terminal.backend_mut(),
+                EnterAlternateScreen,
+                EnableMouseCapture
+            )?;
+            continue;
+        }
+
+        // Handle the QEMU smoke-test boot with the TUI torn down, same as
+        // confirmation above, since QEMU's serial output interleaves with
+        // our own stdout.
+        if let AppState::TestingBoot(_) = &app.state {
+            disable_raw_mode()?;
+            execute!(
+                terminal.backend_mut(),
+                LeaveAlternateScreen,
+                DisableMouseCapture
+            )?;
+            terminal.show_cursor()?;
+
+            app.run_boot_test()?;
+
+            enable_raw_mode()?;
+            execute!(
+                terminal.backend_mut(),
+                EnterAlternateScreen,
+                EnableMouseCapture
+            )?;
+            continue;
+        }
+
         if let Event::Key(key) = event::read()? {
+            // The cmdline editor has its own two-tier keymap (browsing the
+            // token list vs. typing into a token), so it's handled outside
+            // the flat state/key table below.
+            if let AppState::EditingCmdline(_) = &app.state {
+                match (app.cmdline_edit.clone(), key.code) {
+                    (CmdlineEditAction::None, KeyCode::Esc) => app.cancel_cmdline_edit(),
+                    (CmdlineEditAction::None, KeyCode::Down | KeyCode::Char('j')) => {
+                        app.cmdline_next()
+                    }
+                    (CmdlineEditAction::None, KeyCode::Up | KeyCode::Char('k')) => {
+                        app.cmdline_previous()
+                    }
+                    (CmdlineEditAction::None, KeyCode::Char('a')) => app.cmdline_start_add(),
+                    (CmdlineEditAction::None, KeyCode::Char('d')) => app.cmdline_delete_selected(),
+                    (CmdlineEditAction::None, KeyCode::Enter) => app.cmdline_start_edit_selected(),
+                    (CmdlineEditAction::None, KeyCode::Char('s')) => {
+                        disable_raw_mode()?;
+                        execute!(
+                            terminal.backend_mut(),
+                            LeaveAlternateScreen,
+                            DisableMouseCapture
+                        )?;
+                        terminal.show_cursor()?;
+
+                        app.apply_cmdline_and_load()?;
+
+                        if let AppState::EditingCmdline(_) = app.state {
+                            enable_raw_mode()?;
+                            execute!(
+                                terminal.backend_mut(),
+                                EnterAlternateScreen,
+                                EnableMouseCapture
+                            )?;
+                        }
+                    }
+                    (CmdlineEditAction::Adding(_), KeyCode::Enter)
+                    | (CmdlineEditAction::Editing(_, _), KeyCode::Enter) => {
+                        app.cmdline_input_confirm()
+                    }
+                    (CmdlineEditAction::Adding(_), KeyCode::Esc)
+                    | (CmdlineEditAction::Editing(_, _), KeyCode::Esc) => {
+                        app.cmdline_input_cancel()
+                    }
+                    (CmdlineEditAction::Adding(_), KeyCode::Backspace)
+                    | (CmdlineEditAction::Editing(_, _), KeyCode::Backspace) => {
+                        app.cmdline_input_backspace()
+                    }
+                    (CmdlineEditAction::Adding(_), KeyCode::Char(c))
+                    | (CmdlineEditAction::Editing(_, _), KeyCode::Char(c)) => {
+                        app.cmdline_input_char(c)
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+
+            if let AppState::ActionMenu = &app.state {
+                match key.code {
+                    KeyCode::Esc => app.cancel_action_menu(),
+                    KeyCode::Down | KeyCode::Char('j') => app.action_menu_next(),
+                    KeyCode::Up | KeyCode::Char('k') => app.action_menu_previous(),
+                    KeyCode::Enter => {
+                        disable_raw_mode()?;
+                        execute!(
+                            terminal.backend_mut(),
+                            LeaveAlternateScreen,
+                            DisableMouseCapture
+                        )?;
+                        terminal.show_cursor()?;
+
+                        app.run_action_menu_selection()?;
+
+                        if app.state == AppState::ActionMenu {
+                            enable_raw_mode()?;
+                            execute!(
+                                terminal.backend_mut(),
+                                EnterAlternateScreen,
+                                EnableMouseCapture
+                            )?;
+                        }
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+
             match (&app.state, key.code) {
                 (AppState::SelectingKernel, KeyCode::Char('q') | KeyCode::Esc) => return Ok(()),
                 (AppState::SelectingKernel, KeyCode::Down | KeyCode::Char('j')) => app.next(),
                 (AppState::SelectingKernel, KeyCode::Up | KeyCode::Char('k')) => app.previous(),
+                (AppState::SelectingKernel, KeyCode::Char('t')) => app.start_boot_test(),
+                (AppState::SelectingKernel, KeyCode::Char('e')) => app.start_editing_cmdline(),
+                (AppState::SelectingKernel, KeyCode::Char('m')) => app.start_action_menu(),
                 (AppState::SelectingKernel, KeyCode::Enter) => {
                     // Temporarily exit TUI for loading
                     disable_raw_mode()?;
@@ -356,7 +1413,11 @@ fn run_app<B: Backend + std::io::Write>(
 }
 
 fn ui(f: &mut Frame, app: &App) {
-    render_kernel_selection(f, app);
+    match &app.state {
+        AppState::EditingCmdline(_) => render_cmdline_editor(f, app),
+        AppState::ActionMenu => render_action_menu(f, app),
+        _ => render_kernel_selection(f, app),
+    }
 }
 
 fn render_kernel_selection(f: &mut Frame, app: &App) {
@@ -384,12 +1445,12 @@ fn render_kernel_selection(f: &mut Frame, app: &App) {
     let items: Vec<ListItem> = app
         .kernel_versions
         .iter()
-        .map(|version| {
-            let is_current = app.current_kernel.as_ref() == Some(version);
+        .map(|entry| {
+            let is_current = app.current_kernel.as_deref() == Some(entry.version.as_str());
             let display_text = if is_current {
-                format!("  {} (current)", version)
+                format!("  {} (current)", entry.display_name)
             } else {
-                format!("  {}", version)
+                format!("  {}", entry.display_name)
             };
 
             let style = if is_current {
@@ -421,7 +1482,118 @@ fn render_kernel_selection(f: &mut Frame, app: &App) {
     f.render_stateful_widget(items, chunks[1], &mut app.list_state.clone());
 
     // Instructions
-    let instructions = Paragraph::new("Use ↑/↓ or j/k to navigate, Enter to select, q/Esc to quit")
+    let instructions = Paragraph::new(
+        "↑/↓ or j/k to navigate, Enter to select, t to test-boot, e to edit cmdline, m for menu, q/Esc to quit",
+    )
+        .style(Style::default().fg(Color::Gray))
+        .block(Block::default().borders(Borders::ALL).title("Instructions"));
+    f.render_widget(instructions, chunks[2]);
+}
+
+fn render_cmdline_editor(f: &mut Frame, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Min(0),
+            Constraint::Length(3),
+            Constraint::Length(3),
+        ])
+        .split(f.area());
+
+    let version = match &app.state {
+        AppState::EditingCmdline(version) => version.clone(),
+        _ => String::new(),
+    };
+
+    let title = Paragraph::new(format!("Editing cmdline for kernel {}", version))
+        .style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )
+        .block(Block::default().borders(Borders::ALL));
+    f.render_widget(title, chunks[0]);
+
+    let items: Vec<ListItem> = app
+        .cmdline_tokens
+        .iter()
+        .map(|token| ListItem::new(Line::from(Span::raw(format!("  {}", token)))))
+        .collect();
+
+    let items = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Command-line tokens"),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(Color::LightGreen)
+                .fg(Color::Black)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol(">> ");
+
+    f.render_stateful_widget(items, chunks[1], &mut app.cmdline_list_state.clone());
+
+    let input_text = match &app.cmdline_edit {
+        CmdlineEditAction::Adding(s) => format!("New token: {}_", s),
+        CmdlineEditAction::Editing(_, s) => format!("Edit token: {}_", s),
+        CmdlineEditAction::None => String::new(),
+    };
+    let input = Paragraph::new(input_text)
+        .style(Style::default().fg(Color::Yellow))
+        .block(Block::default().borders(Borders::ALL).title("Input"));
+    f.render_widget(input, chunks[2]);
+
+    let instructions = Paragraph::new(
+        "a add, d delete, Enter edit token, s save & load, Esc cancel/back",
+    )
+    .style(Style::default().fg(Color::Gray))
+    .block(Block::default().borders(Borders::ALL).title("Instructions"));
+    f.render_widget(instructions, chunks[3]);
+}
+
+fn render_action_menu(f: &mut Frame, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Min(0),
+            Constraint::Length(3),
+        ])
+        .split(f.area());
+
+    let title = Paragraph::new("Power / Boot Actions")
+        .style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )
+        .block(Block::default().borders(Borders::ALL));
+    f.render_widget(title, chunks[0]);
+
+    let items: Vec<ListItem> = ACTION_MENU_ITEMS
+        .iter()
+        .map(|item| ListItem::new(Line::from(Span::raw(format!("  {}", item.label())))))
+        .collect();
+
+    let items = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title("Actions"))
+        .highlight_style(
+            Style::default()
+                .bg(Color::LightGreen)
+                .fg(Color::Black)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol(">> ");
+
+    f.render_stateful_widget(items, chunks[1], &mut app.action_menu_state.clone());
+
+    let instructions = Paragraph::new("↑/↓ or j/k to navigate, Enter to run, Esc to go back")
         .style(Style::default().fg(Color::Gray))
         .block(Block::default().borders(Borders::ALL).title("Instructions"));
     f.render_widget(instructions, chunks[2]);